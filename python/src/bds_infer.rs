@@ -0,0 +1,383 @@
+//! Automatic Comm-B register inference.
+//!
+//! A DF20/DF21 reply carries a 56-bit MB field whose register (BDS code)
+//! is not transmitted anywhere in the frame: the receiver has to guess
+//! it from the bit pattern itself. Each `is_bdsXX` test below is a cheap
+//! structural check against one register's layout (status bits gating
+//! their associated value, values in a physically sane range); running
+//! the whole cascade against the same MB field and keeping whichever
+//! registers pass is far cheaper than fully decoding every candidate.
+
+/// Extracts an unsigned bitfield `[start, start+len)` (0 = MSB of the
+/// first byte) out of a 56-bit MB field given as 7 bytes.
+fn bits(mb: &[u8], start: usize, len: usize) -> u32 {
+    let mut value = 0u32;
+    for i in start..start + len {
+        let byte = mb[i / 8];
+        let bit = (byte >> (7 - i % 8)) & 1;
+        value = (value << 1) | bit as u32;
+    }
+    value
+}
+
+/// BDS 2,0 — Aircraft identification. The register carries its own BDS
+/// code as the first byte, and the remaining 48 bits are 8 six-bit IA-5
+/// characters that must all fall in the valid callsign subset
+/// (`A`-`Z`, `0`-`9`, space) and not be entirely padding.
+fn is_bds20(mb: &[u8]) -> bool {
+    if mb[0] != 0x20 {
+        return false;
+    }
+    let mut any_char = false;
+    for i in 0..8 {
+        let c = bits(mb, 8 + i * 6, 6);
+        let valid = matches!(c, 1..=26 | 48..=57 | 32);
+        if !valid {
+            return false;
+        }
+        if c != 32 {
+            any_char = true;
+        }
+    }
+    any_char
+}
+
+/// BDS 4,0 — Selected vertical intention. Each of the three selected
+/// altitude/pressure subfields starts with a status bit; when that bit
+/// is 0 the value bits it gates must be all-zero, and when it is 1 the
+/// value must both be nonzero and, once converted to its physical unit,
+/// sit within an operationally plausible range.
+fn is_bds40(mb: &[u8]) -> bool {
+    let altitude_fields = [
+        (0usize, 1usize, 12usize), // MCP/FCU selected altitude
+        (13, 1, 12),               // FMS selected altitude
+    ];
+    for (status_bit, status_len, value_len) in altitude_fields {
+        let status = bits(mb, status_bit, status_len);
+        let value = bits(mb, status_bit + status_len, value_len);
+        if status == 1 {
+            let feet = value as f64 * 16.0;
+            if value == 0 || feet > 50_000.0 {
+                return false;
+            }
+        } else if value != 0 {
+            return false;
+        }
+    }
+
+    let pressure_status = bits(mb, 26, 1);
+    let pressure = bits(mb, 27, 12);
+    if pressure_status == 1 {
+        let millibars = 750.0 + pressure as f64 * 0.1;
+        if !(800.0..=1100.0).contains(&millibars) {
+            return false;
+        }
+    } else if pressure != 0 {
+        return false;
+    }
+
+    true
+}
+
+/// BDS 5,0 — Track and turn report. Roll angle, track angle, ground
+/// speed, track angle rate and true airspeed each have a status bit;
+/// active fields must additionally sit within a physically plausible
+/// range.
+fn is_bds50(mb: &[u8]) -> bool {
+    let roll_status = bits(mb, 0, 1);
+    let roll = bits(mb, 1, 10) as i32;
+    if roll_status == 1 {
+        let roll_deg = (roll - if roll >= 512 { 1024 } else { 0 }) as f64 * 45.0 / 256.0;
+        if roll_deg.abs() > 60.0 {
+            return false;
+        }
+    } else if roll != 0 {
+        return false;
+    }
+
+    let track_status = bits(mb, 11, 1);
+    let track = bits(mb, 12, 11) as i32;
+    if track_status == 1 {
+        let track_deg = (track - if track >= 1024 { 2048 } else { 0 }) as f64 * 90.0 / 512.0;
+        if !(-180.0..=180.0).contains(&track_deg) {
+            return false;
+        }
+    } else if track != 0 {
+        return false;
+    }
+
+    let gs_status = bits(mb, 23, 1);
+    let gs = bits(mb, 24, 10);
+    if gs_status == 1 {
+        let knots = gs as f64 * 2.0;
+        if knots > 600.0 {
+            return false;
+        }
+    } else if gs != 0 {
+        return false;
+    }
+
+    let track_rate_status = bits(mb, 34, 1);
+    let track_rate = bits(mb, 35, 10) as i32;
+    if track_rate_status == 1 {
+        let deg_per_sec =
+            (track_rate - if track_rate >= 512 { 1024 } else { 0 }) as f64 * 8.0 / 256.0;
+        if deg_per_sec.abs() > 16.0 {
+            return false;
+        }
+    } else if track_rate != 0 {
+        return false;
+    }
+
+    let tas_status = bits(mb, 45, 1);
+    let tas = bits(mb, 46, 10);
+    if tas_status == 1 {
+        let knots = tas as f64 * 2.0;
+        if knots > 600.0 {
+            return false;
+        }
+    } else if tas != 0 {
+        return false;
+    }
+
+    true
+}
+
+/// BDS 6,0 — Heading and speed report. Magnetic heading, indicated
+/// airspeed, Mach number and both vertical-rate sources each carry a
+/// status bit gating their value.
+fn is_bds60(mb: &[u8]) -> bool {
+    let heading_status = bits(mb, 0, 1);
+    let heading = bits(mb, 1, 11) as i32;
+    if heading_status == 1 {
+        let heading_deg = (heading - if heading >= 1024 { 2048 } else { 0 }) as f64 * 90.0 / 512.0;
+        if !(-180.0..=180.0).contains(&heading_deg) {
+            return false;
+        }
+    } else if heading != 0 {
+        return false;
+    }
+
+    let ias_status = bits(mb, 12, 1);
+    let ias = bits(mb, 13, 10);
+    if ias_status == 1 {
+        if ias > 500 {
+            return false;
+        }
+    } else if ias != 0 {
+        return false;
+    }
+
+    let mach_status = bits(mb, 23, 1);
+    let mach = bits(mb, 24, 10);
+    if mach_status == 1 {
+        let m = mach as f64 * 2.048 / 512.0;
+        if m > 1.0 {
+            return false;
+        }
+    } else if mach != 0 {
+        return false;
+    }
+
+    let baro_rate_status = bits(mb, 34, 1);
+    let baro_rate = bits(mb, 35, 10) as i32;
+    if baro_rate_status == 1 {
+        let fpm = (baro_rate - if baro_rate >= 512 { 1024 } else { 0 }) as f64 * 32.0;
+        if fpm.abs() > 6000.0 {
+            return false;
+        }
+    } else if baro_rate != 0 {
+        return false;
+    }
+
+    let ivv_status = bits(mb, 45, 1);
+    let ivv = bits(mb, 46, 10) as i32;
+    if ivv_status == 1 {
+        let fpm = (ivv - if ivv >= 512 { 1024 } else { 0 }) as f64 * 32.0;
+        if fpm.abs() > 6000.0 {
+            return false;
+        }
+    } else if ivv != 0 {
+        return false;
+    }
+
+    true
+}
+
+/// BDS 4,4 — Meteorological routine air report. Only two checks are
+/// made: the wind status bit must gate the wind speed bits, and the
+/// always-transmitted temperature must sit in a sane range. The wind
+/// direction, pressure, humidity and turbulence subfields are not
+/// checked.
+fn is_bds44(mb: &[u8]) -> bool {
+    let wind_status = bits(mb, 0, 1);
+    let wind_speed = bits(mb, 1, 9);
+    if wind_status == 0 && wind_speed != 0 {
+        return false;
+    }
+
+    let temp = bits(mb, 24, 11) as i32;
+    let temp_unsigned = temp - if temp >= 1024 { 2048 } else { 0 };
+    let celsius = temp_unsigned as f64 * 0.25;
+    (-80.0..=60.0).contains(&celsius)
+}
+
+/// BDS 4,5 — Meteorological hazard report. Turbulence, wind shear,
+/// microburst, icing and wake vortex each carry a status bit gating a
+/// 2-bit severity code, the same shape as the selected-altitude
+/// subfields of BDS 4,0.
+fn is_bds45(mb: &[u8]) -> bool {
+    let fields = [
+        (0usize, 1usize, 2usize), // turbulence
+        (3, 1, 2),                // wind shear
+        (6, 1, 2),                // microburst
+        (9, 1, 2),                // icing
+        (12, 1, 2),               // wake vortex
+    ];
+    for (status_bit, status_len, value_len) in fields {
+        let status = bits(mb, status_bit, status_len);
+        let value = bits(mb, status_bit + status_len, value_len);
+        if status == 0 && value != 0 {
+            return false;
+        }
+    }
+    true
+}
+
+/// A candidate register for an MB field, with a confidence in `(0, 1]`.
+/// A field that passes exactly one test is unambiguous (confidence
+/// `1.0`); fields that pass several share the weight evenly between the
+/// survivors.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BdsCandidate {
+    pub register: &'static str,
+    pub confidence: f32,
+}
+
+/// Runs the full cascade of validity tests against a 56-bit MB field
+/// (given as 7 bytes) and returns every register that passed, most
+/// confident first.
+pub fn infer(mb: &[u8]) -> Vec<BdsCandidate> {
+    let tests: [(&str, fn(&[u8]) -> bool); 6] = [
+        ("BDS20", is_bds20),
+        ("BDS40", is_bds40),
+        ("BDS50", is_bds50),
+        ("BDS60", is_bds60),
+        ("BDS44", is_bds44),
+        ("BDS45", is_bds45),
+    ];
+
+    let survivors: Vec<&str> = tests
+        .iter()
+        .filter(|(_, test)| test(mb))
+        .map(|(name, _)| *name)
+        .collect();
+
+    let confidence = 1.0 / survivors.len().max(1) as f32;
+    survivors
+        .into_iter()
+        .map(|register| BdsCandidate {
+            register,
+            confidence,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn callsign_register_is_recognised() {
+        // BDS 2,0 MB field encoding callsign "HBFWT3IR".
+        let mb = hex::decode("202021975332522220").unwrap();
+        let candidates = infer(&mb[..7]);
+        assert!(candidates.iter().any(|c| c.register == "BDS20"));
+    }
+
+    #[test]
+    fn all_zero_field_is_ambiguous_but_not_bds20() {
+        let mb = [0u8; 7];
+        let candidates = infer(&mb);
+        assert!(candidates.len() > 1);
+        assert!(!candidates.iter().any(|c| c.register == "BDS20"));
+    }
+
+    /// Writes `value` into bits `[start, start+len)` of a 56-bit MB field.
+    fn set_bits(mb: &mut [u8; 7], start: usize, len: usize, value: u32) {
+        for i in 0..len {
+            let bit = (value >> (len - 1 - i)) & 1;
+            let idx = start + i;
+            let byte = &mut mb[idx / 8];
+            let mask = 1u8 << (7 - idx % 8);
+            if bit == 1 {
+                *byte |= mask;
+            } else {
+                *byte &= !mask;
+            }
+        }
+    }
+
+    #[test]
+    fn bds50_rejects_out_of_range_ground_speed() {
+        let mut mb = [0u8; 7];
+        set_bits(&mut mb, 23, 1, 1);
+        set_bits(&mut mb, 24, 10, 1000); // 2000 kt, not physically plausible
+        assert!(!is_bds50(&mb));
+    }
+
+    #[test]
+    fn bds50_accepts_plausible_ground_speed() {
+        let mut mb = [0u8; 7];
+        set_bits(&mut mb, 23, 1, 1);
+        set_bits(&mut mb, 24, 10, 200); // 400 kt
+        assert!(is_bds50(&mb));
+    }
+
+    #[test]
+    fn bds60_rejects_unset_status_with_nonzero_heading() {
+        let mut mb = [0u8; 7];
+        set_bits(&mut mb, 0, 1, 0);
+        set_bits(&mut mb, 1, 11, 5);
+        assert!(!is_bds60(&mb));
+    }
+
+    #[test]
+    fn bds60_rejects_unset_status_with_nonzero_vertical_rate() {
+        let mut mb = [0u8; 7];
+        set_bits(&mut mb, 45, 1, 0);
+        set_bits(&mut mb, 46, 10, 5);
+        assert!(!is_bds60(&mb));
+    }
+
+    #[test]
+    fn bds40_rejects_out_of_range_selected_altitude() {
+        let mut mb = [0u8; 7];
+        set_bits(&mut mb, 0, 1, 1);
+        set_bits(&mut mb, 1, 12, 4095); // 65520 ft, far above any selectable altitude
+        assert!(!is_bds40(&mb));
+    }
+
+    #[test]
+    fn bds40_accepts_plausible_selected_altitude() {
+        let mut mb = [0u8; 7];
+        set_bits(&mut mb, 0, 1, 1);
+        set_bits(&mut mb, 1, 12, 2500); // 40000 ft
+        assert!(is_bds40(&mb));
+    }
+
+    #[test]
+    fn bds40_accepts_a_full_three_field_message() {
+        // MCP alt 40000 ft, FMS alt 40000 ft, pressure 900 mb, all active
+        // and in range — exercises all three subfields together so an
+        // offset bug in one can't hide behind the others being all-zero.
+        let mut mb = [0u8; 7];
+        set_bits(&mut mb, 0, 1, 1);
+        set_bits(&mut mb, 1, 12, 2500); // 40000 ft
+        set_bits(&mut mb, 13, 1, 1);
+        set_bits(&mut mb, 14, 12, 2500); // 40000 ft
+        set_bits(&mut mb, 26, 1, 1);
+        set_bits(&mut mb, 27, 12, 1500); // 900 mb
+        assert!(is_bds40(&mb));
+    }
+}
@@ -0,0 +1,203 @@
+//! Beast binary and AVR text frame ingestion.
+//!
+//! The hex-based decode entry points in [`crate`] assume the caller has already
+//! stripped a receiver feed down to a bare hex payload string. In
+//! practice dump1090/readsb speak one of two wire formats instead: AVR
+//! text (`*8D...;` or `@`-prefixed with a 6-byte MLAT timestamp) or
+//! Beast binary (`0x1a` frame markers, a type byte, a 6-byte 12 MHz
+//! timestamp and a 1-byte signal level ahead of the frame, with `0x1a`
+//! itself byte-stuffed inside the payload). This module splits a raw
+//! feed into [`RawFrame`]s so the existing `Message::from_bytes` /
+//! `TimedMessage` path can take over from there.
+
+/// One frame pulled out of a Beast or AVR stream, with whatever timing
+/// and signal information the wire format carried alongside it.
+pub struct RawFrame {
+    pub frame: Vec<u8>,
+    pub timestamp: Option<f64>,
+    pub signal: Option<u8>,
+}
+
+/// Beast clock runs at 12 MHz; its 48-bit counter is converted to
+/// seconds the same way for both the Beast and AVR (MLAT) timestamps.
+const BEAST_CLOCK_HZ: f64 = 12e6;
+
+fn ticks_to_seconds(ticks: u64) -> f64 {
+    ticks as f64 / BEAST_CLOCK_HZ
+}
+
+/// Parses one AVR line, e.g. `*8D40621D...;` or
+/// `@0016A4F5C5008D40621D...;`. Returns `None` if the line has neither
+/// a `*` nor a `@` marker, or its payload isn't valid hex.
+pub fn parse_avr_line(line: &str) -> Option<RawFrame> {
+    let line = line.trim().trim_end_matches(';');
+
+    if let Some(rest) = line.strip_prefix('@') {
+        if rest.len() < 12 {
+            return None;
+        }
+        let (ts_hex, payload) = rest.split_at(12);
+        let ticks = u64::from_str_radix(ts_hex, 16).ok()?;
+        let frame = hex::decode(payload).ok()?;
+        return Some(RawFrame {
+            frame,
+            timestamp: Some(ticks_to_seconds(ticks)),
+            signal: None,
+        });
+    }
+
+    let payload = line.strip_prefix('*')?;
+    let frame = hex::decode(payload).ok()?;
+    Some(RawFrame {
+        frame,
+        timestamp: None,
+        signal: None,
+    })
+}
+
+/// Splits a whole AVR text dump (one frame per line) into [`RawFrame`]s,
+/// silently skipping lines that don't parse.
+pub fn parse_avr(text: &str) -> Vec<RawFrame> {
+    text.lines().filter_map(parse_avr_line).collect()
+}
+
+const BEAST_ESCAPE: u8 = 0x1a;
+
+/// Beast message types and the frame length (in bytes) each carries:
+/// `0x31` Mode A/C (2 bytes), `0x32` Mode S short (7 bytes), `0x33`
+/// Mode S long (14 bytes).
+fn beast_frame_len(msg_type: u8) -> Option<usize> {
+    match msg_type {
+        0x31 => Some(2),
+        0x32 => Some(7),
+        0x33 => Some(14),
+        _ => None,
+    }
+}
+
+/// Splits a raw Beast binary stream into [`RawFrame`]s, undoing the
+/// `0x1a 0x1a` byte-stuffing used to escape literal `0x1a` bytes inside
+/// the timestamp/signal/frame payload.
+pub fn parse_beast(bytes: &[u8]) -> Vec<RawFrame> {
+    let mut frames = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] != BEAST_ESCAPE {
+            i += 1;
+            continue;
+        }
+        let Some(&msg_type) = bytes.get(i + 1) else {
+            break;
+        };
+        let Some(frame_len) = beast_frame_len(msg_type) else {
+            i += 1;
+            continue;
+        };
+
+        let needed = 6 + 1 + frame_len;
+        let mut unescaped = Vec::with_capacity(needed);
+        let mut j = i + 2;
+        let mut resync = false;
+        while unescaped.len() < needed {
+            match bytes.get(j) {
+                None => break, // ran out of input mid-frame
+                Some(&BEAST_ESCAPE) => {
+                    if bytes.get(j + 1) == Some(&BEAST_ESCAPE) {
+                        unescaped.push(BEAST_ESCAPE);
+                        j += 2;
+                    } else {
+                        // A lone (non-doubled) 0x1a always marks the
+                        // start of the next message, never data. This
+                        // frame is short/corrupt; abandon it and
+                        // resume parsing right here instead of reading
+                        // through the marker.
+                        resync = true;
+                        break;
+                    }
+                }
+                Some(&b) => {
+                    unescaped.push(b);
+                    j += 1;
+                }
+            }
+        }
+
+        if resync {
+            i = j;
+            continue;
+        }
+        if unescaped.len() < needed {
+            break; // truncated frame at the end of the buffer
+        }
+
+        let ticks = unescaped[0..6]
+            .iter()
+            .fold(0u64, |acc, &b| (acc << 8) | b as u64);
+        let signal = unescaped[6];
+        let frame = unescaped[7..7 + frame_len].to_vec();
+
+        if msg_type != 0x31 {
+            // Mode A/C replies carry no DF/ICAO payload rs1090 decodes.
+            frames.push(RawFrame {
+                frame,
+                timestamp: Some(ticks_to_seconds(ticks)),
+                signal: Some(signal),
+            });
+        }
+        i = j;
+    }
+    frames
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn avr_plain_line_has_no_timestamp() {
+        let raw = parse_avr_line("*8D406B902015A678D4D220AA4BDA;").unwrap();
+        assert_eq!(raw.frame.len(), 14);
+        assert!(raw.timestamp.is_none());
+    }
+
+    #[test]
+    fn avr_mlat_line_carries_a_timestamp() {
+        let raw =
+            parse_avr_line("@0016A4F5C5008D406B902015A678D4D220AA4BDA;")
+                .unwrap();
+        assert_eq!(raw.frame.len(), 14);
+        assert!(raw.timestamp.is_some());
+    }
+
+    #[test]
+    fn beast_unescapes_stuffed_bytes() {
+        let mut stream = vec![BEAST_ESCAPE, 0x32]; // Mode S short, 7-byte frame
+        stream.extend([0, 0, 0, 0, 0, BEAST_ESCAPE, BEAST_ESCAPE]); // timestamp with a stuffed byte
+        stream.push(0xAB); // signal
+        stream.extend([1, 2, 3, 4, 5, 6, 7]); // frame
+        let frames = parse_beast(&stream);
+        assert_eq!(frames.len(), 1);
+        assert_eq!(frames[0].frame, vec![1, 2, 3, 4, 5, 6, 7]);
+        assert_eq!(frames[0].signal, Some(0xAB));
+    }
+
+    #[test]
+    fn lone_escape_resyncs_instead_of_being_read_as_data() {
+        // A short/corrupt first message (truncated signal byte) whose
+        // would-be payload is interrupted by the next message's marker;
+        // the lone 0x1a must not be swallowed as frame data.
+        let mut stream = vec![BEAST_ESCAPE, 0x32];
+        stream.extend([0, 0, 0, 0, 0, 0]); // timestamp, no stuffing
+        // no signal/frame bytes here -- straight into the next marker
+        stream.push(BEAST_ESCAPE);
+        stream.push(0x32);
+        stream.extend([0, 0, 0, 0, 0, 0]); // timestamp
+        stream.push(0xAB); // signal
+        stream.extend([1, 2, 3, 4, 5, 6, 7]); // frame
+
+        let frames = parse_beast(&stream);
+        assert_eq!(frames.len(), 1);
+        assert_eq!(frames[0].frame, vec![1, 2, 3, 4, 5, 6, 7]);
+        assert_eq!(frames[0].signal, Some(0xAB));
+    }
+}
@@ -1,11 +1,20 @@
 #![allow(rustdoc::missing_crate_level_docs)]
 
+mod arrow_output;
+mod bds_infer;
+mod crc;
+mod frames;
+mod objects;
+
 use std::collections::HashMap;
 
+use arrow::pyarrow::IntoPyArrow;
+use arrow::record_batch::{RecordBatch, RecordBatchIterator, RecordBatchReader};
 use pyo3::exceptions::{PyAssertionError, PyValueError};
 use pyo3::prelude::*;
 use rayon::prelude::*;
 use regex::Regex;
+use serde::Serialize;
 use rs1090::data::patterns::PATTERNS;
 use rs1090::data::tail::tail;
 use rs1090::decode::bds::bds05::AirbornePosition;
@@ -27,17 +36,118 @@ use rs1090::decode::flarm::Flarm;
 use rs1090::prelude::*;
 
 #[pyfunction]
-fn decode_1090(msg: String) -> PyResult<Vec<u8>> {
-    let bytes = hex::decode(msg)
+#[pyo3(signature = (msg, correct=false))]
+fn decode_1090(msg: String, correct: bool) -> PyResult<Vec<u8>> {
+    let mut bytes = hex::decode(msg)
         .map_err(|e| DecodeError(DekuError::Parse(e.to_string().into())))?;
     if let Ok((_, msg)) = Message::from_bytes((&bytes, 0)) {
         let pkl = serde_pickle::to_vec(&msg, Default::default())
             .map_err(|e| DecodeError(DekuError::Parse(e.to_string().into())))?;
-        Ok(pkl)
-    } else {
-        Ok([128, 4, 78, 46].to_vec()) // None
+        return Ok(pkl);
+    }
+    if correct && !bytes.is_empty() {
+        let df = bytes[0] >> 3;
+        if let crc::CrcOutcome::Corrected(bits) = crc::check(&bytes, df, true) {
+            crc::apply_correction(&mut bytes, &bits);
+            if let Ok((_, msg)) = Message::from_bytes((&bytes, 0)) {
+                let pkl = serde_pickle::to_vec(&msg, Default::default())
+                    .map_err(|e| {
+                        DecodeError(DekuError::Parse(e.to_string().into()))
+                    })?;
+                return Ok(pkl);
+            }
+        }
+    }
+    Ok([128, 4, 78, 46].to_vec()) // None
+}
+
+/// Same as [`decode_1090`], but returns a native Python dict instead of
+/// a pickled blob, saving callers the extra unpickling round trip.
+#[pyfunction]
+#[pyo3(signature = (msg, correct=false))]
+fn decode_1090_dict(
+    py: Python<'_>,
+    msg: String,
+    correct: bool,
+) -> PyResult<PyObject> {
+    let mut bytes = hex::decode(msg)
+        .map_err(|e| DecodeError(DekuError::Parse(e.to_string().into())))?;
+    if let Ok((_, msg)) = Message::from_bytes((&bytes, 0)) {
+        return objects::into_py(py, &msg);
+    }
+    if correct && !bytes.is_empty() {
+        let df = bytes[0] >> 3;
+        if let crc::CrcOutcome::Corrected(bits) = crc::check(&bytes, df, true) {
+            crc::apply_correction(&mut bytes, &bits);
+            if let Ok((_, msg)) = Message::from_bytes((&bytes, 0)) {
+                return objects::into_py(py, &msg);
+            }
+        }
+    }
+    Ok(py.None())
+}
+
+/// Result of [`crc_check`]: whether a frame's parity was clean, an
+/// ICAO address recovered from an AP field, a set of bits that were
+/// flipped to repair it, or a syndrome that matched no 1- or 2-bit
+/// error pattern.
+#[pyclass]
+#[derive(Clone)]
+struct CrcResult {
+    #[pyo3(get)]
+    clean: bool,
+    #[pyo3(get)]
+    icao24: Option<String>,
+    #[pyo3(get)]
+    corrected_bits: Option<Vec<usize>>,
+    #[pyo3(get)]
+    unrecoverable: bool,
+}
+
+impl From<crc::CrcOutcome> for CrcResult {
+    fn from(outcome: crc::CrcOutcome) -> Self {
+        match outcome {
+            crc::CrcOutcome::Clean => CrcResult {
+                clean: true,
+                icao24: None,
+                corrected_bits: None,
+                unrecoverable: false,
+            },
+            crc::CrcOutcome::Address(addr) => CrcResult {
+                clean: true,
+                icao24: Some(format!("{addr:06x}")),
+                corrected_bits: None,
+                unrecoverable: false,
+            },
+            crc::CrcOutcome::Corrected(bits) => CrcResult {
+                clean: false,
+                icao24: None,
+                corrected_bits: Some(bits),
+                unrecoverable: false,
+            },
+            crc::CrcOutcome::Unrecoverable => CrcResult {
+                clean: false,
+                icao24: None,
+                corrected_bits: None,
+                unrecoverable: true,
+            },
+        }
     }
 }
+
+/// Validates (and attempts to repair) the CRC-24 of a hex-encoded Mode S
+/// frame, without decoding its payload.
+#[pyfunction]
+fn crc_check(msg: String) -> PyResult<CrcResult> {
+    let bytes = hex::decode(msg)
+        .map_err(|e| DecodeError(DekuError::Parse(e.to_string().into())))?;
+    let Some(&first) = bytes.first() else {
+        return Ok(crc::CrcOutcome::Unrecoverable.into());
+    };
+    let df = first >> 3;
+    Ok(crc::check(&bytes, df, true).into())
+}
+
 struct DecodeError(DekuError);
 
 impl From<DecodeError> for PyErr {
@@ -279,6 +389,47 @@ fn decode_bds65(msg: String) -> PyResult<Vec<u8>> {
     }
 }
 
+/// Infers which Comm-B register a DF20/DF21 MB field most plausibly
+/// carries, returning every register that passed the validity cascade
+/// as `(register, confidence)` pairs, most confident first. A message
+/// passing exactly one register's tests comes back with confidence
+/// `1.0`; ambiguous ones share the weight between all survivors.
+#[pyfunction]
+fn infer_bds(msg: String) -> PyResult<Vec<(String, f32)>> {
+    let bytes = hex::decode(msg)
+        .map_err(|e| DecodeError(DekuError::Parse(e.to_string().into())))?;
+    if bytes.len() < 11 {
+        return Err(PyValueError::new_err(
+            "message too short to contain a 56-bit MB field",
+        ));
+    }
+    Ok(bds_infer::infer(&bytes[4..11])
+        .into_iter()
+        .map(|c| (c.register.to_string(), c.confidence))
+        .collect())
+}
+
+/// Vectorized, rayon-parallel form of [`infer_bds`].
+#[pyfunction]
+fn infer_bds_vec(msgs: Vec<String>) -> PyResult<Vec<Vec<(String, f32)>>> {
+    msgs.par_iter()
+        .map(|msg| {
+            let bytes = hex::decode(msg).map_err(|e| {
+                DecodeError(DekuError::Parse(e.to_string().into()))
+            })?;
+            if bytes.len() < 11 {
+                return Err(PyValueError::new_err(
+                    "message too short to contain a 56-bit MB field",
+                ));
+            }
+            Ok(bds_infer::infer(&bytes[4..11])
+                .into_iter()
+                .map(|c| (c.register.to_string(), c.confidence))
+                .collect())
+        })
+        .collect()
+}
+
 #[pyfunction]
 fn decode_1090_vec(msgs_set: Vec<Vec<String>>) -> PyResult<Vec<u8>> {
     let res: Vec<Option<Message>> = msgs_set
@@ -306,6 +457,36 @@ fn decode_1090_vec(msgs_set: Vec<Vec<String>>) -> PyResult<Vec<u8>> {
     Ok(pkl)
 }
 
+/// Same as [`decode_1090_vec`], but returns a native Python list of
+/// dicts instead of a pickled blob.
+#[pyfunction]
+fn decode_1090_vec_dict(
+    py: Python<'_>,
+    msgs_set: Vec<Vec<String>>,
+) -> PyResult<PyObject> {
+    let res: Vec<Option<Message>> = msgs_set
+        .par_iter()
+        .map(|msgs| {
+            msgs.iter()
+                .map(|msg| {
+                    let bytes = hex::decode(msg)
+                        .map_err(|e| {
+                            DecodeError(DekuError::Parse(e.to_string().into()))
+                        })
+                        .ok()?;
+                    if let Ok((_, msg)) = Message::from_bytes((&bytes, 0)) {
+                        Some(msg)
+                    } else {
+                        None
+                    }
+                })
+                .collect()
+        })
+        .flat_map(|v: Vec<Option<Message>>| v)
+        .collect();
+    objects::into_py(py, &res)
+}
+
 #[pyfunction]
 #[pyo3(signature = (msgs_set, ts_set, reference=None))]
 fn decode_1090t_vec(
@@ -353,6 +534,155 @@ fn decode_1090t_vec(
     Ok(pkl)
 }
 
+/// Same as [`decode_1090t_vec`], but returns a native Python list of
+/// dicts instead of a pickled blob.
+#[pyfunction]
+#[pyo3(signature = (msgs_set, ts_set, reference=None))]
+fn decode_1090t_vec_dict(
+    py: Python<'_>,
+    msgs_set: Vec<Vec<String>>,
+    ts_set: Vec<Vec<f64>>,
+    reference: Option<[f64; 2]>,
+) -> PyResult<PyObject> {
+    let mut res: Vec<TimedMessage> = msgs_set
+        .par_iter()
+        .zip(ts_set)
+        .map(|(msgs, ts)| {
+            msgs.iter()
+                .zip(ts)
+                .filter_map(|(msg, timestamp)| {
+                    let bytes = hex::decode(msg)
+                        .map_err(|e| {
+                            DecodeError(DekuError::Parse(e.to_string().into()))
+                        })
+                        .ok()?;
+                    if let Ok((_, message)) = Message::from_bytes((&bytes, 0)) {
+                        Some(TimedMessage {
+                            timestamp,
+                            frame: bytes,
+                            message: Some(message),
+                            metadata: vec![],
+                            decode_time: None,
+                        })
+                    } else {
+                        None
+                    }
+                })
+                .collect()
+        })
+        .flat_map(|v: Vec<TimedMessage>| v)
+        .collect();
+
+    let position = reference.map(|[latitude, longitude]| Position {
+        latitude,
+        longitude,
+    });
+    decode_positions(&mut res, position, &None);
+
+    objects::into_py(py, &res)
+}
+
+/// Hands `batch` to Python as a `pyarrow.Table` through the Arrow C
+/// stream interface, so its column buffers are not copied.
+fn record_batch_to_pyarrow_table(
+    py: Python<'_>,
+    batch: RecordBatch,
+) -> PyResult<PyObject> {
+    let schema = batch.schema();
+    let reader: Box<dyn RecordBatchReader + Send> =
+        Box::new(RecordBatchIterator::new(vec![Ok(batch)], schema));
+    reader.into_pyarrow(py)?.call_method0(py, "read_all")
+}
+
+/// Same as [`decode_1090t_vec`], but returns a zero-copy `pyarrow.Table`
+/// instead of a pickled blob, avoiding the per-message re-parse that
+/// building a DataFrame out of pickled objects costs at file scale.
+#[pyfunction]
+#[pyo3(signature = (msgs_set, ts_set, reference=None))]
+fn decode_1090t_vec_arrow(
+    py: Python<'_>,
+    msgs_set: Vec<Vec<String>>,
+    ts_set: Vec<Vec<f64>>,
+    reference: Option<[f64; 2]>,
+) -> PyResult<PyObject> {
+    let mut res: Vec<TimedMessage> = msgs_set
+        .par_iter()
+        .zip(ts_set)
+        .map(|(msgs, ts)| {
+            msgs.iter()
+                .zip(ts)
+                .filter_map(|(msg, timestamp)| {
+                    let bytes = hex::decode(msg)
+                        .map_err(|e| {
+                            DecodeError(DekuError::Parse(e.to_string().into()))
+                        })
+                        .ok()?;
+                    if let Ok((_, message)) = Message::from_bytes((&bytes, 0)) {
+                        Some(TimedMessage {
+                            timestamp,
+                            frame: bytes,
+                            message: Some(message),
+                            metadata: vec![],
+                            decode_time: None,
+                        })
+                    } else {
+                        None
+                    }
+                })
+                .collect()
+        })
+        .flat_map(|v: Vec<TimedMessage>| v)
+        .collect();
+
+    let position = reference.map(|[latitude, longitude]| Position {
+        latitude,
+        longitude,
+    });
+    decode_positions(&mut res, position, &None);
+
+    let rows: Vec<arrow_output::Row> = res
+        .into_iter()
+        .map(|tm| arrow_output::Row {
+            timestamp: Some(tm.timestamp),
+            message: tm.message,
+        })
+        .collect();
+    let batch = arrow_output::to_record_batch(&rows)
+        .map_err(|e| PyValueError::new_err(e.to_string()))?;
+    record_batch_to_pyarrow_table(py, batch)
+}
+
+/// Same as [`decode_1090_vec`], but returns a zero-copy `pyarrow.Table`.
+#[pyfunction]
+fn decode_1090_vec_arrow(
+    py: Python<'_>,
+    msgs_set: Vec<Vec<String>>,
+) -> PyResult<PyObject> {
+    let messages: Vec<Option<Message>> = msgs_set
+        .par_iter()
+        .map(|msgs| {
+            msgs.iter()
+                .map(|msg| {
+                    let bytes = hex::decode(msg).ok()?;
+                    Message::from_bytes((&bytes, 0)).ok().map(|(_, m)| m)
+                })
+                .collect::<Vec<Option<Message>>>()
+        })
+        .flat_map(|v: Vec<Option<Message>>| v)
+        .collect();
+
+    let rows: Vec<arrow_output::Row> = messages
+        .into_iter()
+        .map(|message| arrow_output::Row {
+            timestamp: None,
+            message,
+        })
+        .collect();
+    let batch = arrow_output::to_record_batch(&rows)
+        .map_err(|e| PyValueError::new_err(e.to_string()))?;
+    record_batch_to_pyarrow_table(py, batch)
+}
+
 #[pyfunction]
 fn decode_flarm(
     msg: String,
@@ -372,6 +702,26 @@ fn decode_flarm(
     }
 }
 
+/// Same as [`decode_flarm`], but returns a native Python dict instead
+/// of a pickled blob.
+#[pyfunction]
+fn decode_flarm_dict(
+    py: Python<'_>,
+    msg: String,
+    ts: u32,
+    reflat: f64,
+    reflon: f64,
+) -> PyResult<PyObject> {
+    let bytes = hex::decode(msg)
+        .map_err(|e| DecodeError(DekuError::Parse(e.to_string().into())))?;
+    let reference = [reflat, reflon];
+    if let Ok(msg) = Flarm::from_record(ts, &reference, &bytes) {
+        objects::into_py(py, &msg)
+    } else {
+        Ok(py.None())
+    }
+}
+
 #[pyfunction]
 fn decode_flarm_vec(
     msgs_set: Vec<Vec<String>>,
@@ -421,6 +771,180 @@ fn decode_flarm_vec(
     Ok(pkl)
 }
 
+/// Same as [`decode_flarm_vec`], but returns a native Python list of
+/// dicts instead of a pickled blob.
+#[pyfunction]
+fn decode_flarm_vec_dict(
+    py: Python<'_>,
+    msgs_set: Vec<Vec<String>>,
+    ts_set: Vec<Vec<u32>>,
+    ref_lat: Vec<Vec<f64>>,
+    ref_lon: Vec<Vec<f64>>,
+) -> PyResult<PyObject> {
+    let reference: Vec<Vec<[f64; 2]>> = ref_lat
+        .iter()
+        .zip(ref_lon.iter())
+        .map(|(lat, lon)| {
+            lat.iter()
+                .zip(lon.iter())
+                .map(|(lat, lon)| [*lat, *lon])
+                .collect()
+        })
+        .collect();
+    let res: Vec<Flarm> = msgs_set
+        .par_iter()
+        .zip(ts_set)
+        .zip(reference)
+        .map(|((msgs, ts), reference)| {
+            msgs.iter()
+                .zip(ts)
+                .zip(reference)
+                .filter_map(|((msg, timestamp), reference)| {
+                    let bytes = hex::decode(msg)
+                        .map_err(|e| {
+                            DecodeError(DekuError::Parse(e.to_string().into()))
+                        })
+                        .ok()?;
+                    if let Ok(flarm) =
+                        Flarm::from_record(timestamp, &reference, &bytes)
+                    {
+                        Some(flarm)
+                    } else {
+                        None
+                    }
+                })
+                .collect()
+        })
+        .flat_map(|v: Vec<Flarm>| v)
+        .collect();
+
+    objects::into_py(py, &res)
+}
+
+/// A decoded, positioned message paired with whatever signal level the
+/// wire format carried alongside its frame (Beast carries one; AVR text
+/// never does, so it's always `None` there).
+#[derive(Serialize)]
+struct DecodedFrame {
+    message: TimedMessage,
+    signal: Option<u8>,
+}
+
+fn raw_frames_to_messages(
+    raw: Vec<frames::RawFrame>,
+    reference: Option<[f64; 2]>,
+) -> Vec<DecodedFrame> {
+    let mut timed = Vec::new();
+    let mut signals = Vec::new();
+    for raw in raw {
+        let Ok((_, message)) = Message::from_bytes((&raw.frame, 0)) else {
+            continue;
+        };
+        signals.push(raw.signal);
+        timed.push(TimedMessage {
+            timestamp: raw.timestamp.unwrap_or(0.0),
+            frame: raw.frame,
+            message: Some(message),
+            metadata: vec![],
+            decode_time: None,
+        });
+    }
+
+    let position = reference.map(|[latitude, longitude]| Position {
+        latitude,
+        longitude,
+    });
+    decode_positions(&mut timed, position, &None);
+
+    timed
+        .into_iter()
+        .zip(signals)
+        .map(|(message, signal)| DecodedFrame { message, signal })
+        .collect()
+}
+
+/// Decodes a raw Beast binary stream (as produced by dump1090/readsb)
+/// straight to a pickled `list[DecodedFrame]`, extracting each frame's
+/// embedded 12 MHz timestamp and signal level along the way.
+#[pyfunction]
+#[pyo3(signature = (data, reference=None))]
+fn decode_beast(data: Vec<u8>, reference: Option<[f64; 2]>) -> PyResult<Vec<u8>> {
+    let res = raw_frames_to_messages(frames::parse_beast(&data), reference);
+    let pkl = serde_pickle::to_vec(&res, Default::default())
+        .map_err(|e| DecodeError(DekuError::Parse(e.to_string().into())))?;
+    Ok(pkl)
+}
+
+/// Same as [`decode_beast`], but returns a native Python list of dicts.
+#[pyfunction]
+#[pyo3(signature = (data, reference=None))]
+fn decode_beast_dict(
+    py: Python<'_>,
+    data: Vec<u8>,
+    reference: Option<[f64; 2]>,
+) -> PyResult<PyObject> {
+    let res = raw_frames_to_messages(frames::parse_beast(&data), reference);
+    objects::into_py(py, &res)
+}
+
+/// Rayon-parallel form of [`decode_beast`] over several independent
+/// Beast streams (e.g. one per receiver).
+#[pyfunction]
+#[pyo3(signature = (data_set, reference=None))]
+fn decode_beast_vec(
+    data_set: Vec<Vec<u8>>,
+    reference: Option<[f64; 2]>,
+) -> PyResult<Vec<u8>> {
+    let res: Vec<DecodedFrame> = data_set
+        .par_iter()
+        .flat_map(|data| raw_frames_to_messages(frames::parse_beast(data), reference))
+        .collect();
+    let pkl = serde_pickle::to_vec(&res, Default::default())
+        .map_err(|e| DecodeError(DekuError::Parse(e.to_string().into())))?;
+    Ok(pkl)
+}
+
+/// Decodes an AVR text dump (`*...;` / `@...;` lines, one frame per
+/// line) straight to a pickled `list[DecodedFrame]`, extracting the
+/// embedded MLAT timestamp when the `@` form is used.
+#[pyfunction]
+#[pyo3(signature = (text, reference=None))]
+fn decode_avr(text: String, reference: Option<[f64; 2]>) -> PyResult<Vec<u8>> {
+    let res = raw_frames_to_messages(frames::parse_avr(&text), reference);
+    let pkl = serde_pickle::to_vec(&res, Default::default())
+        .map_err(|e| DecodeError(DekuError::Parse(e.to_string().into())))?;
+    Ok(pkl)
+}
+
+/// Same as [`decode_avr`], but returns a native Python list of dicts.
+#[pyfunction]
+#[pyo3(signature = (text, reference=None))]
+fn decode_avr_dict(
+    py: Python<'_>,
+    text: String,
+    reference: Option<[f64; 2]>,
+) -> PyResult<PyObject> {
+    let res = raw_frames_to_messages(frames::parse_avr(&text), reference);
+    objects::into_py(py, &res)
+}
+
+/// Rayon-parallel form of [`decode_avr`] over several independent AVR
+/// text dumps.
+#[pyfunction]
+#[pyo3(signature = (text_set, reference=None))]
+fn decode_avr_vec(
+    text_set: Vec<String>,
+    reference: Option<[f64; 2]>,
+) -> PyResult<Vec<u8>> {
+    let res: Vec<DecodedFrame> = text_set
+        .par_iter()
+        .flat_map(|text| raw_frames_to_messages(frames::parse_avr(text), reference))
+        .collect();
+    let pkl = serde_pickle::to_vec(&res, Default::default())
+        .map_err(|e| DecodeError(DekuError::Parse(e.to_string().into())))?;
+    Ok(pkl)
+}
+
 #[pyfunction]
 #[pyo3(signature = (icao24, registration=None))]
 fn aircraft_information(
@@ -498,7 +1022,32 @@ fn _rust(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(decode_flarm, m)?)?;
     m.add_function(wrap_pyfunction!(decode_flarm_vec, m)?)?;
 
+    // Native-object variants (return a dict/list instead of a pickled blob)
+    m.add_function(wrap_pyfunction!(decode_1090_dict, m)?)?;
+    m.add_function(wrap_pyfunction!(decode_1090_vec_dict, m)?)?;
+    m.add_function(wrap_pyfunction!(decode_1090t_vec_dict, m)?)?;
+    m.add_function(wrap_pyfunction!(decode_flarm_dict, m)?)?;
+    m.add_function(wrap_pyfunction!(decode_flarm_vec_dict, m)?)?;
+
+    // Zero-copy Arrow output
+    m.add_function(wrap_pyfunction!(decode_1090_vec_arrow, m)?)?;
+    m.add_function(wrap_pyfunction!(decode_1090t_vec_arrow, m)?)?;
+
+    // Beast binary / AVR text frame ingestion
+    m.add_function(wrap_pyfunction!(decode_beast, m)?)?;
+    m.add_function(wrap_pyfunction!(decode_beast_dict, m)?)?;
+    m.add_function(wrap_pyfunction!(decode_beast_vec, m)?)?;
+    m.add_function(wrap_pyfunction!(decode_avr, m)?)?;
+    m.add_function(wrap_pyfunction!(decode_avr_dict, m)?)?;
+    m.add_function(wrap_pyfunction!(decode_avr_vec, m)?)?;
+
+    // CRC-24 validation and repair
+    m.add_function(wrap_pyfunction!(crc_check, m)?)?;
+    m.add_class::<CrcResult>()?;
+
     // Comm-B BDS inference
+    m.add_function(wrap_pyfunction!(infer_bds, m)?)?;
+    m.add_function(wrap_pyfunction!(infer_bds_vec, m)?)?;
     m.add_function(wrap_pyfunction!(decode_bds05, m)?)?;
     m.add_function(wrap_pyfunction!(decode_bds10, m)?)?;
     m.add_function(wrap_pyfunction!(decode_bds17, m)?)?;
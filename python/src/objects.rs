@@ -0,0 +1,23 @@
+//! Native Python result objects.
+//!
+//! The pickle-based `decode_*` entry points in [`crate`] serialize their decoded
+//! struct with `serde_pickle` and hands Python an opaque byte blob that
+//! has to be unpickled again on the other side — an extra allocation
+//! and parse per message, and a dependency on the pickle wire format.
+//! `into_py` walks the same `Serialize` impl the pickle path already
+//! requires straight into a native Python object (dict/list/scalar) via
+//! `pythonize`, so callers get a real `dict` without touching pickle at
+//! all.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pythonize::pythonize;
+use serde::Serialize;
+
+/// Converts any decoded, `Serialize` rs1090 struct directly into a
+/// native Python object, bypassing pickle entirely.
+pub fn into_py<T: Serialize>(py: Python<'_>, value: &T) -> PyResult<PyObject> {
+    pythonize(py, value)
+        .map(|bound| bound.unbind())
+        .map_err(|e| PyValueError::new_err(e.to_string()))
+}